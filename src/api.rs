@@ -23,3 +23,51 @@ pub struct Response {
     pub request: Request,
     pub response: Vec<Pass>,
 }
+
+#[derive(Deserialize, Debug)]
+pub struct IssPosition {
+    pub latitude: String,
+    pub longitude: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct IssNowResponse {
+    pub message: String,
+    pub iss_position: IssPosition,
+    pub timestamp: i64,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Person {
+    pub name: String,
+    pub craft: String,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AstrosResponse {
+    pub message: String,
+    pub number: u64,
+    pub people: Vec<Person>,
+}
+
+/// The endpoints offered by `api.open-notify.org`.
+pub enum ApiUrl {
+    /// Upcoming overhead pass times for a ground station.
+    Passes { lat: f64, lon: f64, alt: f64, n: u8 },
+    /// Current subsatellite point of the ISS.
+    IssNow,
+    /// People currently in space.
+    Astros,
+}
+
+/// Build the full request URL for `which` against the `base` URL.
+pub fn api_url(which: ApiUrl, base: &str) -> String {
+    match which {
+        ApiUrl::Passes { lat, lon, alt, n } => format!(
+            "{}/iss/v1/?lat={}&lon={}&altitude={}&n={}",
+            base, lat, lon, alt, n
+        ),
+        ApiUrl::IssNow => format!("{}/iss-now.json", base),
+        ApiUrl::Astros => format!("{}/astros.json", base),
+    }
+}
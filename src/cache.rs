@@ -0,0 +1,32 @@
+//! TTL cache of fetched spotting results keyed on the request URL, so that
+//! identical requests issued within the poll period reuse the last good
+//! `Vec<Spot>` instead of hammering `api.open-notify.org` afresh.
+
+use crate::spot::Spot;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Default time-to-live matching the recommended `90` minute poll period.
+pub const DEFAULT_CACHE_TTL: u64 = 90 * 60;
+
+static CACHE: Lazy<Mutex<HashMap<String, (Instant, Vec<Spot>)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Return the cached `Vec<Spot>` for `url` if the last good fetch is younger than `ttl`.
+pub fn get(url: &str, ttl: Duration) -> Option<Vec<Spot>> {
+    let cache = CACHE.lock().unwrap();
+    match cache.get(url) {
+        Some((at, spots)) if at.elapsed() < ttl => Some(spots.clone()),
+        _ => None,
+    }
+}
+
+/// Store a freshly fetched `Vec<Spot>` for `url`.
+pub fn put(url: &str, spots: &[Spot]) {
+    CACHE
+        .lock()
+        .unwrap()
+        .insert(url.to_string(), (Instant::now(), spots.to_vec()));
+}
@@ -0,0 +1,94 @@
+//! Shared client covering every `api.open-notify.org` endpoint. The pass-times
+//! polling in [`init`](crate::init) is just one consumer of this client; the
+//! `iss-now` and `astros` endpoints are exposed directly.
+
+use crate::api::{self, ApiUrl};
+use crate::error::Error;
+use crate::spot::{from_utc_timestamp, DateTime};
+use http::StatusCode;
+
+/// Default base URL of the *open-notify* service.
+pub const DEFAULT_BASE_URL: &str = "http://api.open-notify.org";
+
+/// Current subsatellite point of the ISS as returned by `iss-now`.
+#[derive(Debug, Clone, Copy)]
+pub struct Position {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub timestamp: DateTime,
+}
+
+/// A person currently in space as returned by `astros`.
+#[derive(Debug, Clone)]
+pub struct Astronaut {
+    pub name: String,
+    pub craft: String,
+}
+
+/// Client that centralizes base-URL construction for all endpoints.
+pub struct Client {
+    base_url: String,
+}
+
+impl Default for Client {
+    fn default() -> Client {
+        Client {
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+}
+
+impl Client {
+    /// Create a client talking to the default [`DEFAULT_BASE_URL`].
+    pub fn new() -> Client {
+        Client::default()
+    }
+    /// Override the base URL (e.g. to point at a mock server).
+    pub fn base_url(mut self, base_url: &str) -> Client {
+        self.base_url = base_url.to_string();
+        self
+    }
+    /// Full request URL for `which` against this client's base URL.
+    pub(crate) fn url(&self, which: ApiUrl) -> String {
+        api::api_url(which, &self.base_url)
+    }
+    /// Issue a blocking GET and return the response body on `200 OK`.
+    fn get(&self, which: ApiUrl) -> Result<String, Error> {
+        let response = reqwest::blocking::get(&self.url(which))?;
+        match response.status() {
+            StatusCode::OK => Ok(response.text()?),
+            status => Err(Error::Http(status)),
+        }
+    }
+    /// Fetch the current subsatellite point of the ISS.
+    pub fn iss_now(&self) -> Result<Position, Error> {
+        let r: api::IssNowResponse = serde_json::from_str(&self.get(ApiUrl::IssNow)?)?;
+        Ok(Position {
+            latitude: r.iss_position.latitude.parse()?,
+            longitude: r.iss_position.longitude.parse()?,
+            timestamp: from_utc_timestamp(r.timestamp),
+        })
+    }
+    /// Fetch the people currently in space.
+    pub fn astros(&self) -> Result<Vec<Astronaut>, Error> {
+        let r: api::AstrosResponse = serde_json::from_str(&self.get(ApiUrl::Astros)?)?;
+        Ok(r
+            .people
+            .into_iter()
+            .map(|p| Astronaut {
+                name: p.name,
+                craft: p.craft,
+            })
+            .collect())
+    }
+}
+
+/// Fetch the current subsatellite point of the ISS via the default client.
+pub fn iss_now() -> Result<Position, Error> {
+    Client::new().iss_now()
+}
+
+/// Fetch the people currently in space via the default client.
+pub fn astros() -> Result<Vec<Astronaut>, Error> {
+    Client::new().astros()
+}
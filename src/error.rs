@@ -0,0 +1,33 @@
+use http::StatusCode;
+use thiserror::Error;
+
+/// Error type that covers every fallible path of this crate.
+///
+/// The `open-notify.org` fetch can fail with an HTTP status, a transport
+/// error from *reqwest* or a JSON parse error from *serde_json*. In addition
+/// a freshly spawned polling thread has not produced a result yet, which is
+/// modelled as [`Error::NotReady`] instead of a magic `"loading..."` string.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// No update has been fetched yet (the polling thread is still loading).
+    #[error("loading...")]
+    NotReady,
+    /// Server answered with a non-`200` status code (e.g. `500 Internal Server Error`).
+    #[error("{0}")]
+    Http(StatusCode),
+    /// Transport layer error while talking to the server.
+    #[error(transparent)]
+    Request(#[from] reqwest::Error),
+    /// Response body could not be parsed as the expected JSON.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+    /// A numeric field of the response could not be parsed.
+    #[error(transparent)]
+    ParseFloat(#[from] std::num::ParseFloatError),
+    /// A ground station parameter was outside its documented range.
+    #[error("value {value} out of range for `{field}`")]
+    OutOfRange { field: &'static str, value: f64 },
+    /// A place name could not be resolved to coordinates.
+    #[error("could not geocode place `{0}`")]
+    Geocode(String),
+}
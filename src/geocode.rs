@@ -0,0 +1,53 @@
+//! Resolve human place names to `(latitude, longitude)` coordinates so
+//! UI-facing callers don't have to supply raw floats. Resolved coordinates are
+//! cached so repeated lookups of the same place don't re-query the backend.
+
+use crate::error::Error;
+use http::StatusCode;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+#[derive(Deserialize)]
+struct GeoResult {
+    latitude: f64,
+    longitude: f64,
+}
+
+#[derive(Deserialize)]
+struct GeoResponse {
+    results: Option<Vec<GeoResult>>,
+}
+
+static CACHE: Lazy<Mutex<HashMap<String, (f64, f64)>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Build the geocoding request URL with the place name percent-encoded, so
+/// multi-word places (`"New York"`) don't produce an invalid URL.
+pub(crate) fn query_url(place: &str) -> Result<reqwest::Url, Error> {
+    reqwest::Url::parse_with_params(
+        "https://geocoding-api.open-meteo.com/v1/search",
+        &[("name", place), ("count", "1")],
+    )
+    .map_err(|_| Error::Geocode(place.to_string()))
+}
+
+/// Resolve a human place name to `(latitude, longitude)`, caching the result.
+pub fn geocode(place: &str) -> Result<(f64, f64), Error> {
+    if let Some(coord) = CACHE.lock().unwrap().get(place) {
+        return Ok(*coord);
+    }
+    let response = reqwest::blocking::get(query_url(place)?)?;
+    let coord = match response.status() {
+        StatusCode::OK => {
+            let r: GeoResponse = serde_json::from_str(&response.text()?)?;
+            match r.results.and_then(|mut v| v.drain(..).next()) {
+                Some(g) => (g.latitude, g.longitude),
+                None => return Err(Error::Geocode(place.to_string())),
+            }
+        }
+        status => return Err(Error::Http(status)),
+    };
+    CACHE.lock().unwrap().insert(place.to_string(), coord);
+    Ok(coord)
+}
@@ -8,17 +8,23 @@ use std::thread;
 use std::time;
 
 mod api;
+mod cache;
+mod client;
+mod error;
+mod geocode;
 mod spot;
+mod weather;
 
 #[cfg(test)]
 mod tests;
 
+pub use client::*;
+pub use error::Error;
 pub use spot::*;
+pub use weather::*;
 
 /// Receiver object you get from `init()` and have top handle to `update()`.
-pub type Receiver = mpsc::Receiver<Result<Vec<Spot>, String>>;
-/// Loading error messaage you get at the first call of `update()`.
-pub const LOADING: &str = "loading...";
+pub type Receiver = mpsc::Receiver<Result<Vec<Spot>, Error>>;
 
 /// Spawns a thread which fetches the current ISS spotting from
 /// [http://api.open-notify.org](https://http://api.open-notify.org) periodically.
@@ -35,52 +41,173 @@ pub const LOADING: &str = "loading...";
 ///
 ///    The return value is a `mpsc` *channel receiver*:
 ///    ```rust
-///     pub type Receiver = std::sync::mpsc::Receiver<Result<open_notify::Spot, String>>;
+///     pub type Receiver = std::sync::mpsc::Receiver<Result<Vec<open_notify::Spot>, open_notify::Error>>;
 ///    ```
 pub fn init(latitude: f64, longitude: f64, altitude: f64, n: u8, poll_mins: u64) -> Receiver {
-    // generate correct request URL depending on city is id or name
-    let url = format!(
-        "http://api.open-notify.org/iss/v1/?lat={}&lon={}&altitude={}&n={}",
-        latitude, longitude, altitude, n
-    );
-    // fork thread that continuously fetches ISS spotting updates every <poll_mins> minutes
-    let period = time::Duration::from_secs(60 * poll_mins);
-    let (tx, rx) = mpsc::channel();
-    thread::spawn(move || {
-        tx.send(Err(LOADING.to_string())).unwrap_or(());
-        loop {
-            match reqwest::blocking::get(&url) {
-                Ok(response) => match response.status() {
-                    StatusCode::OK => {
-                        let text = response.text().unwrap();
-                        match serde_json::from_str(&text) {
-                            Ok(w) => {
-                                let mut result = Vec::new();
-                                // convert response into Vec<Spot>
-                                let w: api::Response = w;
-                                for r in w.response {
-                                    result.push(Spot {
-                                        duration: Duration::seconds(r.duration as i64),
-                                        risetime: from_utc_timestamp(r.risetime),
-                                    });
-                                }
-                                tx.send(Ok(result)).unwrap_or(());
-                                if period == time::Duration::new(0, 0) {
-                                    break;
+    Builder::new(latitude, longitude)
+        .altitude(altitude)
+        .n(n)
+        .poll_mins(poll_mins)
+        .init()
+}
+
+/// Builder for an ISS spotting poll that carries all ground station parameters
+/// plus the [`cache_ttl`](Builder::cache_ttl) knob controlling how long a fetched
+/// `Vec<Spot>` may be reused before the upstream API is queried again.
+pub struct Builder {
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    n: u8,
+    poll_mins: u64,
+    cache_ttl: time::Duration,
+}
+
+impl Builder {
+    /// Start a builder for the given ground station coordinates.
+    pub fn new(latitude: f64, longitude: f64) -> Builder {
+        Builder {
+            latitude,
+            longitude,
+            altitude: 0.0,
+            n: 100,
+            poll_mins: 0,
+            cache_ttl: time::Duration::from_secs(cache::DEFAULT_CACHE_TTL),
+        }
+    }
+    /// Set the ground station altitude in meters (default `0`).
+    pub fn altitude(mut self, altitude: f64) -> Builder {
+        self.altitude = altitude;
+        self
+    }
+    /// Set the number of spotting events to fetch (default `100`).
+    pub fn n(mut self, n: u8) -> Builder {
+        self.n = n;
+        self
+    }
+    /// Set the poll period in minutes (default `0`, i.e. one shot).
+    pub fn poll_mins(mut self, poll_mins: u64) -> Builder {
+        self.poll_mins = poll_mins;
+        self
+    }
+    /// Set how long (in seconds) a fetched result is reused from the cache
+    /// before a fresh request is issued (default [`cache::DEFAULT_CACHE_TTL`]).
+    pub fn cache_ttl(mut self, secs: u64) -> Builder {
+        self.cache_ttl = time::Duration::from_secs(secs);
+        self
+    }
+    /// Spawn the polling thread and return the *channel receiver* to `update()`.
+    pub fn init(self) -> Receiver {
+        let (tx, rx) = mpsc::channel();
+        // reject out-of-range ground station parameters before spawning a thread
+        if let Err(e) = validate(self.latitude, self.longitude, self.altitude, self.n) {
+            tx.send(Err(e)).unwrap_or(());
+            return rx;
+        }
+        // generate the request URL for the pass-times endpoint via the shared client
+        let url = Client::new().url(api::ApiUrl::Passes {
+            lat: self.latitude,
+            lon: self.longitude,
+            alt: self.altitude,
+            n: self.n,
+        });
+        // fork thread that continuously fetches ISS spotting updates every <poll_mins> minutes
+        let period = time::Duration::from_secs(60 * self.poll_mins);
+        // one-shot fetches always go to the network; only pollers reuse cached results
+        let one_shot = period == time::Duration::new(0, 0);
+        let cache_ttl = self.cache_ttl;
+        thread::spawn(move || {
+            tx.send(Err(Error::NotReady)).unwrap_or(());
+            loop {
+                // reuse the last good result while it is still fresh enough
+                if let Some(spots) = cache::get(&url, cache_ttl).filter(|_| !one_shot) {
+                    tx.send(Ok(spots)).unwrap_or(());
+                    if period == time::Duration::new(0, 0) {
+                        break;
+                    }
+                    thread::sleep(period);
+                    continue;
+                }
+                match reqwest::blocking::get(&url) {
+                    Ok(response) => match response.status() {
+                        StatusCode::OK => {
+                            let text = response.text().unwrap();
+                            match serde_json::from_str::<api::Response>(&text) {
+                                Ok(w) => {
+                                    let mut result = Vec::new();
+                                    // convert response into Vec<Spot>
+                                    for r in w.response {
+                                        result.push(Spot {
+                                            duration: Duration::seconds(r.duration as i64),
+                                            risetime: from_utc_timestamp(r.risetime),
+                                        });
+                                    }
+                                    cache::put(&url, &result);
+                                    tx.send(Ok(result)).unwrap_or(());
+                                    if period == time::Duration::new(0, 0) {
+                                        break;
+                                    }
+                                    thread::sleep(period);
                                 }
-                                thread::sleep(period);
+                                Err(e) => tx.send(Err(Error::Json(e))).unwrap_or(()),
                             }
-                            Err(e) => tx.send(Err(e.to_string())).unwrap_or(()),
                         }
-                    }
-                    _ => tx.send(Err(response.status().to_string())).unwrap_or(()),
-                },
-                Err(_e) => (),
+                        status => tx.send(Err(Error::Http(status))).unwrap_or(()),
+                    },
+                    Err(_e) => (),
+                }
             }
-        }
-    });
-    // return receiver that provides the updated ISS spotting as json string
-    return rx;
+        });
+        // return receiver that provides the updated ISS spotting results
+        return rx;
+    }
+}
+
+/// Validate ground station parameters against their documented ranges.
+fn validate(latitude: f64, longitude: f64, altitude: f64, n: u8) -> Result<(), Error> {
+    if !(-90.0..=90.0).contains(&latitude) {
+        return Err(Error::OutOfRange {
+            field: "latitude",
+            value: latitude,
+        });
+    }
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(Error::OutOfRange {
+            field: "longitude",
+            value: longitude,
+        });
+    }
+    if !(0.0..=10000.0).contains(&altitude) {
+        return Err(Error::OutOfRange {
+            field: "altitude",
+            value: altitude,
+        });
+    }
+    if n > 100 {
+        return Err(Error::OutOfRange {
+            field: "n",
+            value: n as f64,
+        });
+    }
+    Ok(())
+}
+
+/// Like [`init`] but takes a human place name which is geocoded to coordinates first.
+/// #### Parameters
+/// - `place`: human place name of the ground station (e.g. `"Berlin"`).
+/// - `altitude`: altitude in meters of the ground station. optional. Range: 0..10000
+/// - `n`: number of spotting events to fetch (<=100)
+/// - `poll_mins`: see [`init`].
+/// #### Return value
+/// - ⇒ `Ok(Receiver)`: handle this to `update()` as with [`init`].
+/// - ⇒ `Err(Error::Geocode)`: the place name could not be resolved.
+pub fn init_by_place(place: &str, altitude: f64, n: u8, poll_mins: u64) -> Result<Receiver, Error> {
+    let (latitude, longitude) = geocode::geocode(place)?;
+    Ok(Builder::new(latitude, longitude)
+        .altitude(altitude)
+        .n(n)
+        .poll_mins(poll_mins)
+        .init())
 }
 
 /// Get current ISS spotting update that the spawned thread could fetched.
@@ -91,10 +218,11 @@ pub fn init(latitude: f64, longitude: f64, altitude: f64, n: u8, poll_mins: u64)
 /// - ⇒ `Some(Result)`: Update available
 ///     - ⇒ `Ok(Vec<Spot>)`: vector of upcoming spotting events
 ///         (see also [*open-notify* documentation](https://open-notify-api.readthedocs.io/en/latest/iss_pass.html) for details)
-///     - ⇒ `Err(String)`: Error message about any occured http or json issue
-///         - e.g. `500 Internal Server Error"
-///         - some json parser error message if response from open-notify.org could not be parsed
-pub fn update(receiver: &Receiver) -> Option<Result<Vec<Spot>, String>> {
+///     - ⇒ `Err(Error)`: any occured http or json issue
+///         - e.g. `Error::Http(500 Internal Server Error)`
+///         - `Error::NotReady` while the first update is still being fetched
+///         - `Error::Json(..)` if the response from open-notify.org could not be parsed
+pub fn update(receiver: &Receiver) -> Option<Result<Vec<Spot>, Error>> {
     match receiver.try_recv() {
         Ok(spots) => Some(spots),
         Err(_e) => None,
@@ -111,26 +239,38 @@ pub fn update(receiver: &Receiver) -> Option<Result<Vec<Spot>, String>> {
 /// #### Return value
 /// - ⇒ `Ok(Vec<Spot>)`: vector of upcoming spotting events
 ///     (see also [*open-notify* documentation](https://open-notify-api.readthedocs.io/en/latest/iss_pass.html) for details)
-/// - ⇒ `Err(String)`: Error message about any occured http or json issue
-///         - e.g. `500 Internal Server Error"
-///         - some json parser error message if response from open-notify.org could not be parsed
-pub async fn spot(latitude: f64, longitude: f64, altitude: f64, n: u8) -> Result<Vec<Spot>, String> {
+/// - ⇒ `Err(Error)`: any occured http or json issue
+///         - e.g. `Error::Http(500 Internal Server Error)`
+///         - `Error::Json(..)` if the response from open-notify.org could not be parsed
+pub async fn spot(latitude: f64, longitude: f64, altitude: f64, n: u8) -> Result<Vec<Spot>, Error> {
     let r = init(latitude, longitude, altitude, n, 0);
     loop {
         match update(&r) {
             Some(response) => match response {
                 Ok(spots) => return Ok(spots),
-                Err(e) => {
-                    if e != LOADING {
-                        return Err(e);
-                    }
-                }
+                Err(e) => match e {
+                    Error::NotReady => (),
+                    e => return Err(e),
+                },
             },
             None => (),
         }
     }
 }
 
+/// Like [`spot`] but takes a human place name which is geocoded to coordinates first.
+/// #### Parameters
+/// - `place`: human place name of the ground station (e.g. `"Berlin"`).
+/// - `altitude`: altitude in meters of the ground station. optional. Range: 0..10000
+/// - `n`: number of spotting events to fetch (<=100)
+/// #### Return value
+/// - ⇒ `Ok(Vec<Spot>)`: vector of upcoming spotting events
+/// - ⇒ `Err(Error::Geocode)`: the place name could not be resolved.
+pub async fn spot_by_place(place: &str, altitude: f64, n: u8) -> Result<Vec<Spot>, Error> {
+    let (latitude, longitude) = geocode::geocode(place)?;
+    spot(latitude, longitude, altitude, n).await
+}
+
 /// synchronous functions
 pub mod blocking {
     use super::*;
@@ -142,11 +282,24 @@ pub mod blocking {
     /// - `n`: number of spotting events to fetch (<=100)
     /// #### Return value
     /// - ⇒ `Ok(Vec<Spot>)`: vector of upcoming spotting events
-    /// - ⇒ `Err(String)`: Error message about any occured http or json issue
-    ///         - e.g. `500 Internal Server Error"
-    ///         - some json parser error message if response from open-notify.org could not be parsed
-    pub fn spot(latitude: f64, longitude: f64, altitude: f64, n: u8) -> Result<Vec<Spot>, String> {
+    /// - ⇒ `Err(Error)`: any occured http or json issue
+    ///         - e.g. `Error::Http(500 Internal Server Error)`
+    ///         - `Error::Json(..)` if the response from open-notify.org could not be parsed
+    pub fn spot(latitude: f64, longitude: f64, altitude: f64, n: u8) -> Result<Vec<Spot>, Error> {
         // wait for result
         executor::block_on(super::spot(latitude, longitude, altitude, n))
     }
+
+    /// Like [`spot`](self::spot) but takes a human place name which is geocoded first.
+    /// #### Parameters
+    /// - `place`: human place name of the ground station (e.g. `"Berlin"`).
+    /// - `altitude`: altitude in meters of the ground station. optional. Range: 0..10000
+    /// - `n`: number of spotting events to fetch (<=100)
+    /// #### Return value
+    /// - ⇒ `Ok(Vec<Spot>)`: vector of upcoming spotting events
+    /// - ⇒ `Err(Error::Geocode)`: the place name could not be resolved.
+    pub fn spot_by_place(place: &str, altitude: f64, n: u8) -> Result<Vec<Spot>, Error> {
+        // wait for result
+        executor::block_on(super::spot_by_place(place, altitude, n))
+    }
 }
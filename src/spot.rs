@@ -1,14 +1,24 @@
 use chrono;
+use serde::Serialize;
 
 pub type Duration = chrono::Duration;
 pub type DateTime = chrono::DateTime<chrono::Local>;
 
+/// Serialize a [`Duration`] as a whole number of seconds.
+fn serialize_duration_secs<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_i64(duration.num_seconds())
+}
+
 pub fn from_utc_timestamp(t: i64) -> DateTime {
     let t = chrono::NaiveDateTime::from_timestamp(t, 0);
     let t: chrono::DateTime<chrono::Utc> = chrono::DateTime::from_utc(t, chrono::Utc);
     return chrono::DateTime::from(t);
 }
 
+#[derive(Serialize)]
 pub struct DayTime {
     pub sunrise: DateTime,
     pub sunset: DateTime,
@@ -21,14 +31,62 @@ impl DayTime {
             sunset: from_utc_timestamp(sunset_utc),
         }
     }
+    /// Compute sunrise and sunset for a ground station from its coordinates and
+    /// the day of `date`, so visibility filtering no longer needs externally fed
+    /// UTC timestamps. Implements the standard sunrise equation.
+    ///
+    /// Latitudes/days where the sun never rises or sets (polar night/day) are
+    /// surfaced as a no-day `DayTime` (sunrise == sunset) or an all-day `DayTime`
+    /// (sunrise at the start, sunset at the end of the day) respectively.
+    pub fn compute(latitude: f64, longitude: f64, date: DateTime) -> DayTime {
+        use chrono::Datelike;
+        let rad = std::f64::consts::PI / 180.0;
+        let n = date.ordinal() as f64;
+        // solar declination δ (degrees)
+        let decl = -23.44 * (rad * (360.0 / 365.0 * (n + 10.0))).cos();
+        // equation of time (minutes)
+        let b = rad * (360.0 / 364.0 * (n - 81.0));
+        let eot = 9.87 * (2.0 * b).sin() - 7.53 * b.cos() - 1.5 * b.sin();
+        // solar noon in UTC hours
+        let noon = 12.0 - longitude / 15.0 - eot / 60.0;
+        // midnight UTC of this calendar day
+        let midnight = chrono::NaiveDate::from_ymd(date.year(), date.month(), date.day())
+            .and_hms(0, 0, 0)
+            .timestamp();
+        let at = |hours: f64| from_utc_timestamp(midnight + (hours * 3600.0) as i64);
+        // sunrise hour angle ω₀, clamped to the valid domain of acos
+        let cos_w = -(latitude * rad).tan() * (decl * rad).tan();
+        if cos_w < -1.0 {
+            // polar day: the sun never sets
+            return DayTime {
+                sunrise: at(0.0),
+                sunset: at(24.0),
+            };
+        }
+        if cos_w > 1.0 {
+            // polar night: the sun never rises
+            return DayTime {
+                sunrise: at(noon),
+                sunset: at(noon),
+            };
+        }
+        let w0 = cos_w.acos() / rad;
+        DayTime {
+            sunrise: at(noon - w0 / 15.0),
+            sunset: at(noon + w0 / 15.0),
+        }
+    }
     pub fn at_night(&self, datetime: &DateTime) -> bool {
         datetime < &self.sunrise || datetime > &self.sunset
     }
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Serialize)]
 pub struct Spot {
+    /// Length of the pass, serialized as a whole number of seconds.
+    #[serde(serialize_with = "serialize_duration_secs")]
     pub duration: Duration,
+    /// Time the ISS rises above the horizon, serialized as an RFC 3339 timestamp.
     pub risetime: DateTime,
 }
 
@@ -58,6 +116,25 @@ pub fn find_upcoming(spots: &Vec<Spot>, daytime: Option<&DayTime>, now: DateTime
     return None;
 }
 
+/// Export a slice of spotting events as comma separated values with a header
+/// row, `risetime` as an RFC 3339 timestamp and `duration` in seconds.
+pub fn to_csv(spots: &[Spot]) -> String {
+    let mut csv = String::from("risetime,duration\n");
+    for spot in spots {
+        csv.push_str(&format!(
+            "{},{}\n",
+            spot.risetime.to_rfc3339(),
+            spot.duration.num_seconds()
+        ));
+    }
+    return csv;
+}
+
+/// Export a slice of spotting events as a JSON array.
+pub fn to_json(spots: &[Spot]) -> String {
+    serde_json::to_string(spots).unwrap_or_default()
+}
+
 pub fn find_current(spots: &Vec<Spot>, daytime: Option<&DayTime>, now: DateTime) -> Option<Spot> {
     // count upcoming spots
     for spot in spots {
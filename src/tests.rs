@@ -86,6 +86,83 @@ fn test_daytime_many() {
     );
 }
 
+#[test]
+fn test_cache_ttl() {
+    use std::time::Duration;
+    let url = "http://api.open-notify.org/iss/v1/?lat=52.52&lon=13.4&altitude=0&n=1";
+    let s = spots(["01.06.2021 00:00"].to_vec());
+    crate::cache::put(url, &s);
+    // a second identical request within the TTL is served from cache (no re-fetch)
+    assert_eq!(crate::cache::get(url, Duration::from_secs(60)).unwrap().len(), 1);
+    // a zero TTL never reuses the cache
+    assert!(crate::cache::get(url, Duration::from_secs(0)).is_none());
+}
+
+#[test]
+fn test_geocode_url_encoding() {
+    // multi-word place names must be percent-encoded into a valid URL
+    let url = crate::geocode::query_url("New York").unwrap();
+    assert!(!url.as_str().contains("New York"));
+    assert!(url.as_str().contains("name=New+York"));
+    assert!(url.as_str().contains("count=1"));
+}
+
+#[test]
+fn test_export() {
+    let s = spots(["01.06.2021 00:00", "01.06.2021 06:00"].to_vec());
+    // CSV: header row, RFC 3339 risetime, duration in whole seconds
+    let csv = to_csv(&s);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "risetime,duration");
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].contains("2021-06-01T00:00:00"));
+    assert!(lines[1].ends_with(",1800"));
+    // JSON: array of objects with duration as integer seconds
+    let json = to_json(&s);
+    assert!(json.starts_with('['));
+    assert!(json.contains("\"duration\":1800"));
+}
+
+#[test]
+fn test_compute_daytime() {
+    use chrono::{Timelike, Utc};
+    // Berlin, 1st of June 2021: sunrise early morning, sunset late evening (UTC).
+    let dt = DayTime::compute(52.520008, 13.404954, time("01.06.2021 12:00"));
+    let sunrise = dt.sunrise.with_timezone(&Utc);
+    let sunset = dt.sunset.with_timezone(&Utc);
+    let rise_h = sunrise.hour() as f64 + sunrise.minute() as f64 / 60.0;
+    let set_h = sunset.hour() as f64 + sunset.minute() as f64 / 60.0;
+    assert!((rise_h - 2.95).abs() < 0.5, "sunrise {} UTC", sunrise);
+    assert!((set_h - 19.18).abs() < 0.5, "sunset {} UTC", sunset);
+    assert!(dt.sunrise < dt.sunset);
+}
+
+#[test]
+fn test_compute_polar() {
+    // Midsummer at 80°N: the sun never sets (all-day), i.e. sunrise at(0) .. sunset at(24).
+    let midsummer = DayTime::compute(80.0, 0.0, time("21.06.2021 12:00"));
+    assert_eq!(midsummer.sunset - midsummer.sunrise, chrono::Duration::hours(24));
+    // Midwinter at 80°N: the sun never rises (no-day), collapsing to a single instant.
+    let midwinter = DayTime::compute(80.0, 0.0, time("21.12.2021 12:00"));
+    assert_eq!(midwinter.sunrise, midwinter.sunset);
+}
+
+#[test]
+fn test_out_of_range() {
+    assert!(matches!(
+        blocking::spot(91.0, 0.0, 0.0, 100),
+        Err(Error::OutOfRange { field: "latitude", .. })
+    ));
+    assert!(matches!(
+        blocking::spot(52.52, 181.0, 0.0, 100),
+        Err(Error::OutOfRange { field: "longitude", .. })
+    ));
+    assert!(matches!(
+        blocking::spot(52.52, 13.4, 0.0, 101),
+        Err(Error::OutOfRange { field: "n", .. })
+    ));
+}
+
 #[test]
 fn test_daytime_one() {
     let s = spots(["01.06.2021 00:00"].to_vec());
@@ -0,0 +1,112 @@
+//! Optional weather-provider integration used to refine pass selection:
+//! a pass after dark is only actually visible when the sky is clear enough.
+//!
+//! The deserialization structs mirror the `openweathermap`-style forecast
+//! response so the same geolocated `latitude`/`longitude` the crate already
+//! takes can be reused to fetch a cloud-cover forecast.
+
+use crate::error::Error;
+use crate::spot::{DayTime, DateTime, Spot};
+use http::StatusCode;
+use serde::Deserialize;
+
+/// Geo location of a forecast.
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct Coord {
+    pub lon: f64,
+    pub lat: f64,
+}
+
+/// Textual weather condition of a single forecast entry.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Weather {
+    pub id: u64,
+    pub main: String,
+    pub description: String,
+}
+
+/// Atmospheric measurements of a single forecast entry.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Main {
+    pub temp: f64,
+    pub humidity: u8,
+}
+
+/// Cloud coverage in percent of a single forecast entry.
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct Clouds {
+    pub all: u8,
+}
+
+/// A single point of the forecast timeline.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Entry {
+    pub dt: i64,
+    pub main: Main,
+    pub weather: Vec<Weather>,
+    pub clouds: Clouds,
+}
+
+/// Whole forecast response for one location.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Forecast {
+    pub coord: Coord,
+    pub list: Vec<Entry>,
+}
+
+impl Forecast {
+    /// Fetch a cloud-cover forecast for the given ground station coordinates.
+    /// #### Parameters
+    /// - `latitude`/`longitude`: geo location of the ground station.
+    /// - `api_key`: *openweathermap* API key.
+    pub fn fetch(latitude: f64, longitude: f64, api_key: &str) -> Result<Forecast, Error> {
+        let url = format!(
+            "https://api.openweathermap.org/data/2.5/forecast?lat={}&lon={}&appid={}&units=metric",
+            latitude, longitude, api_key
+        );
+        let response = reqwest::blocking::get(&url)?;
+        match response.status() {
+            StatusCode::OK => Ok(serde_json::from_str(&response.text()?)?),
+            status => Err(Error::Http(status)),
+        }
+    }
+
+    /// Forecast entry closest in time to `datetime`, or `None` if the forecast is empty.
+    pub fn nearest(&self, datetime: &DateTime) -> Option<&Entry> {
+        let ts = datetime.timestamp();
+        self.list
+            .iter()
+            .min_by_key(|e| (e.dt - ts).abs())
+    }
+}
+
+/// Visibility configuration used by [`find_visible`].
+#[derive(Copy, Clone)]
+pub struct Visibility {
+    /// Cloud coverage threshold (percent): a pass counts as visible only if its
+    /// forecasted cloud coverage is strictly below this value.
+    pub max_cloud_pct: u8,
+}
+
+/// Find the next upcoming pass that is both after dark and under a clear enough sky.
+///
+/// Keeps only `at_night` passes whose nearest forecast entry reports a cloud
+/// coverage below `visibility.max_cloud_pct`.
+pub fn find_visible(
+    spots: &Vec<Spot>,
+    daytime: &DayTime,
+    weather: &Forecast,
+    visibility: &Visibility,
+    now: DateTime,
+) -> Option<Spot> {
+    for spot in spots {
+        if spot.risetime > now && spot.at_night(daytime) {
+            if let Some(entry) = weather.nearest(&spot.risetime) {
+                if entry.clouds.all < visibility.max_cloud_pct {
+                    return Some(spot.clone());
+                }
+            }
+        }
+    }
+    return None;
+}